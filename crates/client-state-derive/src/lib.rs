@@ -0,0 +1,108 @@
+//! Derives dispatch impls for the crate's `ClientState`/`ConsensusState`
+//! enums and newtype wrappers, so that adding a light client only means
+//! adding an enum variant instead of hand-writing the trait forwarding.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Variant};
+
+mod utils;
+
+use utils::{get_enum_generic_params, get_enum_variant_type_path, get_newtype_struct_type_path};
+
+#[proc_macro_derive(ClientState)]
+pub fn client_state_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    derive_client_state_base(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Builds the `ClientStateBase` dispatch impl for either:
+/// - an enum whose variants each wrap a single field (unnamed or named), or
+/// - a newtype struct wrapping a single field.
+///
+/// Any other shape (unit/named-multi-field structs, unions, ...) is reported
+/// as a spanned `compile_error!` via the `?` operator rather than a panic.
+fn derive_client_state_base(ast: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &ast.ident;
+    let generic_params = get_enum_generic_params(&ast.generics);
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let client_type_body = match &ast.data {
+        Data::Enum(data_enum) => {
+            let arms = data_enum
+                .variants
+                .iter()
+                .map(|variant| variant_arm(name, variant))
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Struct(data_struct) => {
+            let field_type = get_newtype_struct_type_path(data_struct, name)?;
+            quote! {
+                <#field_type as ClientStateBase>::client_type(&self.0)
+            }
+        }
+        Data::Union(data_union) => {
+            return Err(syn::Error::new_spanned(
+                data_union.union_token,
+                format!("#[derive(ClientState)] does not support unions, only enums of \
+                         single-field variants and single-field newtype structs: \"{name}\""),
+            ))
+        }
+    };
+
+    // Every generic type parameter on the enum/struct (e.g. `Tm`, `Near` in
+    // `HostClientState<Tm, Near>`) must itself implement `ClientStateBase`
+    // for the dispatch above to type-check, so the derived impl stays
+    // generic over them instead of only covering a fixed set of concrete
+    // variants baked in at derive time.
+    let generic_bounds = generic_params
+        .iter()
+        .map(|param| quote! { #param: ClientStateBase });
+    let where_clause = match where_clause {
+        Some(where_clause) => {
+            let existing_predicates = where_clause.predicates.iter();
+            quote! { where #(#existing_predicates,)* #(#generic_bounds,)* }
+        }
+        None if generic_params.is_empty() => quote! {},
+        None => quote! { where #(#generic_bounds,)* },
+    };
+
+    Ok(quote! {
+        impl #impl_generics ClientStateBase for #name #ty_generics #where_clause {
+            fn client_type(&self) -> ClientType {
+                #client_type_body
+            }
+        }
+    })
+}
+
+fn variant_arm(enum_name: &syn::Ident, variant: &Variant) -> syn::Result<TokenStream2> {
+    let variant_type = get_enum_variant_type_path(variant)?;
+    let variant_name = &variant.ident;
+
+    let pattern = match &variant.fields {
+        Fields::Unnamed(_) => quote! { #enum_name::#variant_name(state) },
+        Fields::Named(fields) => {
+            let field_name = &fields
+                .named
+                .first()
+                .expect("get_enum_variant_type_path already checked this variant has one field")
+                .ident;
+            quote! { #enum_name::#variant_name { #field_name: state } }
+        }
+        Fields::Unit => unreachable!("get_enum_variant_type_path rejects unit variants"),
+    };
+
+    Ok(quote! {
+        #pattern => <#variant_type as ClientStateBase>::client_type(state),
+    })
+}
@@ -1,7 +1,11 @@
-use syn::{Path, Variant};
+use syn::{DataStruct, Fields, GenericParam, Generics, Ident, Path, Type, Variant};
 
-/// Retrieves the field of a given enum variant. Outputs an error message if the enum variant
-/// is in the wrong format (i.e. isn't an unnamed enum, or contains more than one field).
+/// Retrieves the type path of a given enum variant's sole field. Accepts
+/// either a single unnamed field (`Tendermint(TmClientState)`) or a single
+/// named field (`Tendermint { state: TmClientState }`). Returns a spanned
+/// [`syn::Error`] - rather than panicking - when the variant shape doesn't
+/// match, so the derive macro surfaces a proper `compile_error!` pointing at
+/// the offending variant instead of an opaque proc-macro panic.
 ///
 /// For example, given
 /// ```rust
@@ -11,26 +15,87 @@ use syn::{Path, Variant};
 /// }
 /// ```
 /// when acting on the `Tendermint` variant, this will return `TmClientState`.
-///
-pub fn get_enum_variant_type_path(enum_variant: &Variant) -> &Path {
+pub fn get_enum_variant_type_path(enum_variant: &Variant) -> syn::Result<&Path> {
     let variant_name = &enum_variant.ident;
-    let variant_unnamed_fields = match &enum_variant.fields {
-            syn::Fields::Unnamed(fields) => fields,
-            _ => panic!("\"{variant_name}\" variant must be unnamed, such as `{variant_name}({variant_name}ClientState)`")
-        };
 
-    if variant_unnamed_fields.unnamed.iter().len() != 1 {
-        panic!("\"{variant_name}\" must contain exactly one field, such as `{variant_name}({variant_name}ClientState)`");
-    }
+    let field = match &enum_variant.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            fields.unnamed.first().expect("checked length above")
+        }
+        Fields::Named(fields) if fields.named.len() == 1 => {
+            fields.named.first().expect("checked length above")
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                enum_variant,
+                format!(
+                    "\"{variant_name}\" must contain exactly one field, such as \
+                     `{variant_name}({variant_name}ClientState)` or \
+                     `{variant_name} {{ state: {variant_name}ClientState }}`"
+                ),
+            ))
+        }
+    };
 
-    // A representation of the variant's field (e.g. `TmClientState`). We must dig into
-    // the field to get the `TmClientState` path.
-    let unnamed_field = variant_unnamed_fields.unnamed.first().unwrap();
+    get_type_path(&field.ty).ok_or_else(|| {
+        syn::Error::new_spanned(
+            field,
+            format!(
+                "invalid enum variant \"{variant_name}\" field; please use an explicit, named type"
+            ),
+        )
+    })
+}
 
-    match &unnamed_field.ty {
-        syn::Type::Path(path) => &path.path,
+/// Retrieves the type path of a newtype struct's sole field.
+///
+/// For example, given `struct HostClientState(TmClientState);`, this returns
+/// `TmClientState`.
+pub fn get_newtype_struct_type_path<'a>(
+    data_struct: &'a DataStruct,
+    struct_name: &Ident,
+) -> syn::Result<&'a Path> {
+    let field = match &data_struct.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            fields.unnamed.first().expect("checked length above")
+        }
         _ => {
-            panic!("Invalid enum variant {variant_name} field. Please use an explicit, named type.")
+            return Err(syn::Error::new_spanned(
+                &data_struct.fields,
+                format!(
+                    "\"{struct_name}\" must be a newtype struct with exactly one field, \
+                     such as `struct {struct_name}({struct_name}ClientState);`"
+                ),
+            ))
         }
+    };
+
+    get_type_path(&field.ty).ok_or_else(|| {
+        syn::Error::new_spanned(
+            field,
+            format!("invalid \"{struct_name}\" field; please use an explicit, named type"),
+        )
+    })
+}
+
+/// Returns the enum's generic type parameters (e.g. `Tm` in `HostClientState<Tm>`)
+/// so that derived `ClientState`/`ConsensusState` dispatch impls can stay
+/// generic over them instead of only being generated for a fixed set of
+/// concrete variants.
+pub fn get_enum_generic_params(generics: &Generics) -> Vec<&Ident> {
+    generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(type_param) => Some(&type_param.ident),
+            _ => None,
+        })
+        .collect()
+}
+
+fn get_type_path(ty: &Type) -> Option<&Path> {
+    match ty {
+        Type::Path(path) => Some(&path.path),
+        _ => None,
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,11 @@
+use client_state_derive::ClientState;
+
+pub struct TmClientState;
+pub struct TmConsensusState;
+
+#[derive(ClientState)]
+enum HostClientState {
+    Tendermint(TmClientState, TmConsensusState),
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use client_state_derive::ClientState;
+
+#[derive(ClientState)]
+enum HostClientState {
+    Tendermint,
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+use client_state_derive::ClientState;
+
+struct ClientType;
+
+trait ClientStateBase {
+    fn client_type(&self) -> ClientType;
+}
+
+#[derive(ClientState)]
+enum HostClientState<Tm, Near>
+where
+    Tm: Clone,
+    Near: Clone,
+{
+    Tendermint(Tm),
+    Near(Near),
+}
+
+// The derived `where` clause must merge the enum's own predicates (`Tm:
+// Clone`, `Near: Clone`) with the `ClientStateBase` bounds it adds, rather
+// than concatenating them without a separator.
+fn assert_client_state_base<T: ClientStateBase>() {}
+
+fn main() {
+    #[derive(Clone)]
+    struct TmClientState;
+    impl ClientStateBase for TmClientState {
+        fn client_type(&self) -> ClientType {
+            ClientType
+        }
+    }
+    #[derive(Clone)]
+    struct NearClientState;
+    impl ClientStateBase for NearClientState {
+        fn client_type(&self) -> ClientType {
+            ClientType
+        }
+    }
+
+    assert_client_state_base::<HostClientState<TmClientState, NearClientState>>();
+}
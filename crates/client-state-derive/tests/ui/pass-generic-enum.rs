@@ -0,0 +1,35 @@
+use client_state_derive::ClientState;
+
+struct ClientType;
+
+trait ClientStateBase {
+    fn client_type(&self) -> ClientType;
+}
+
+#[derive(ClientState)]
+enum HostClientState<Tm, Near> {
+    Tendermint(Tm),
+    Near(Near),
+}
+
+// The derived impl is generic over `Tm`/`Near` themselves, bounded by
+// `ClientStateBase`, rather than being generated only for one concrete pair
+// of client state types.
+fn assert_client_state_base<T: ClientStateBase>() {}
+
+fn main() {
+    struct TmClientState;
+    impl ClientStateBase for TmClientState {
+        fn client_type(&self) -> ClientType {
+            ClientType
+        }
+    }
+    struct NearClientState;
+    impl ClientStateBase for NearClientState {
+        fn client_type(&self) -> ClientType {
+            ClientType
+        }
+    }
+
+    assert_client_state_base::<HostClientState<TmClientState, NearClientState>>();
+}
@@ -0,0 +1,21 @@
+use client_state_derive::ClientState;
+
+struct ClientType;
+
+trait ClientStateBase {
+    fn client_type(&self) -> ClientType;
+}
+
+pub struct TmClientState;
+impl ClientStateBase for TmClientState {
+    fn client_type(&self) -> ClientType {
+        ClientType
+    }
+}
+
+#[derive(ClientState)]
+enum HostClientState {
+    Tendermint { state: TmClientState },
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use client_state_derive::ClientState;
+
+struct ClientType;
+
+trait ClientStateBase {
+    fn client_type(&self) -> ClientType;
+}
+
+pub struct TmClientState;
+impl ClientStateBase for TmClientState {
+    fn client_type(&self) -> ClientType {
+        ClientType
+    }
+}
+
+pub struct NearClientState;
+impl ClientStateBase for NearClientState {
+    fn client_type(&self) -> ClientType {
+        ClientType
+    }
+}
+
+#[derive(ClientState)]
+enum HostClientState {
+    Tendermint(TmClientState),
+    Near(NearClientState),
+}
+
+fn main() {}
@@ -0,0 +1,197 @@
+use crate::applications::nft_transfer::acknowledgement::NftTransferAcknowledgement;
+use crate::applications::nft_transfer::{ClassId, ClassUri, TokenIds, TokenUris, MODULE_ID_STR};
+use crate::events::ModuleEvent;
+use crate::prelude::*;
+use crate::signer::Signer;
+
+const EVENT_TYPE_PACKET: &str = "non_fungible_token_packet";
+const EVENT_TYPE_TIMEOUT: &str = "timeout";
+const EVENT_TYPE_CLASS_TRACE: &str = "class_trace";
+const EVENT_TYPE_TRANSFER: &str = "ibc_nft_transfer";
+
+pub enum Event {
+    Recv(RecvEvent),
+    Ack(AckEvent),
+    AckStatus(AckStatusEvent),
+    Timeout(TimeoutEvent),
+    ClassTrace(ClassTraceEvent),
+    Transfer(TransferEvent),
+}
+
+pub struct RecvEvent {
+    pub sender: Signer,
+    pub receiver: Signer,
+    pub class_id: ClassId,
+    pub class_uri: Option<ClassUri>,
+    pub token_ids: TokenIds,
+    pub token_uris: TokenUris,
+    pub success: bool,
+}
+
+impl From<RecvEvent> for ModuleEvent {
+    fn from(ev: RecvEvent) -> Self {
+        let RecvEvent {
+            sender,
+            receiver,
+            class_id,
+            class_uri,
+            token_ids,
+            token_uris,
+            success,
+        } = ev;
+        let mut ev = Self {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("sender", sender).into(),
+                ("receiver", receiver).into(),
+                ("class_id", class_id).into(),
+                ("token_ids", token_ids).into(),
+                ("token_uris", token_uris).into(),
+                ("success", success).into(),
+            ],
+        };
+        if let Some(class_uri) = class_uri {
+            ev.attributes.push(("class_uri", class_uri).into());
+        }
+        ev
+    }
+}
+
+pub struct AckEvent {
+    pub sender: Signer,
+    pub receiver: Signer,
+    pub class_id: ClassId,
+    pub token_ids: TokenIds,
+    pub acknowledgement: NftTransferAcknowledgement,
+}
+
+impl From<AckEvent> for ModuleEvent {
+    fn from(ev: AckEvent) -> Self {
+        let AckEvent {
+            sender,
+            receiver,
+            class_id,
+            token_ids,
+            acknowledgement,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("sender", sender).into(),
+                ("receiver", receiver).into(),
+                ("class_id", class_id).into(),
+                ("token_ids", token_ids).into(),
+                ("acknowledgement", acknowledgement).into(),
+            ],
+        }
+    }
+}
+
+pub struct AckStatusEvent {
+    pub acknowledgement: NftTransferAcknowledgement,
+}
+
+impl From<AckStatusEvent> for ModuleEvent {
+    fn from(ev: AckStatusEvent) -> Self {
+        let AckStatusEvent { acknowledgement } = ev;
+        let attr_label = match acknowledgement {
+            NftTransferAcknowledgement::Success(_) => "success",
+            NftTransferAcknowledgement::Error(_) => "error",
+        };
+
+        Self {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes: vec![(attr_label, acknowledgement.to_string()).into()],
+        }
+    }
+}
+
+pub struct TimeoutEvent {
+    pub refund_receiver: Signer,
+    pub refund_class_id: ClassId,
+    pub refund_token_ids: TokenIds,
+}
+
+impl From<TimeoutEvent> for ModuleEvent {
+    fn from(ev: TimeoutEvent) -> Self {
+        let TimeoutEvent {
+            refund_receiver,
+            refund_class_id,
+            refund_token_ids,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_TIMEOUT.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("refund_receiver", refund_receiver).into(),
+                ("refund_class_id", refund_class_id).into(),
+                ("refund_token_ids", refund_token_ids).into(),
+            ],
+        }
+    }
+}
+
+pub struct ClassTraceEvent {
+    pub trace_hash: Option<String>,
+    pub class_id: ClassId,
+}
+
+impl From<ClassTraceEvent> for ModuleEvent {
+    fn from(ev: ClassTraceEvent) -> Self {
+        let ClassTraceEvent {
+            trace_hash,
+            class_id,
+        } = ev;
+        let mut ev = Self {
+            kind: EVENT_TYPE_CLASS_TRACE.to_string(),
+            attributes: vec![("class_id", class_id).into()],
+        };
+        if let Some(hash) = trace_hash {
+            ev.attributes.push(("trace_hash", hash).into());
+        }
+        ev
+    }
+}
+
+pub struct TransferEvent {
+    pub sender: Signer,
+    pub receiver: Signer,
+    pub class_id: ClassId,
+    pub token_ids: TokenIds,
+}
+
+impl From<TransferEvent> for ModuleEvent {
+    fn from(ev: TransferEvent) -> Self {
+        let TransferEvent {
+            sender,
+            receiver,
+            class_id,
+            token_ids,
+        } = ev;
+
+        Self {
+            kind: EVENT_TYPE_TRANSFER.to_string(),
+            attributes: vec![
+                ("sender", sender).into(),
+                ("receiver", receiver).into(),
+                ("class_id", class_id).into(),
+                ("token_ids", token_ids).into(),
+            ],
+        }
+    }
+}
+
+impl From<Event> for ModuleEvent {
+    fn from(ev: Event) -> Self {
+        match ev {
+            Event::Recv(ev) => ev.into(),
+            Event::Ack(ev) => ev.into(),
+            Event::AckStatus(ev) => ev.into(),
+            Event::Timeout(ev) => ev.into(),
+            Event::ClassTrace(ev) => ev.into(),
+            Event::Transfer(ev) => ev.into(),
+        }
+    }
+}
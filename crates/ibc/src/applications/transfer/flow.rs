@@ -0,0 +1,98 @@
+//! A type-state wrapper around [`Event`] that encodes the packet lifecycle
+//! (`Transfer` -> `Recv` -> `Ack`/`Timeout`) in the type system, so that
+//! emitting events out of order is a compile error rather than a handler bug.
+
+use core::marker::PhantomData;
+
+use crate::applications::transfer::events::{
+    AckEvent, AckStatusEvent, Event, RecvEvent, TimeoutEvent, TransferEvent,
+};
+use crate::events::ModuleEvent;
+
+/// The packet has been sent on the source chain; a [`TransferEvent`] has been
+/// emitted, and the flow awaits a receive, an acknowledgement or a timeout.
+pub struct Sent;
+
+/// The packet has been received on the counterparty chain; a [`RecvEvent`]
+/// has been emitted, and the flow awaits acknowledgement or timeout.
+pub struct Received;
+
+/// The packet has been acknowledged back on the source chain; an
+/// [`AckEvent`]/[`AckStatusEvent`] has been emitted. Terminal state.
+pub struct Acknowledged;
+
+/// The packet timed out without being acknowledged; a [`TimeoutEvent`] has
+/// been emitted. Terminal state.
+pub struct TimedOut;
+
+/// A packet-lifecycle reporter that carries the current state as a
+/// zero-sized phantom type, so that, for example, `.acknowledge(..)` after
+/// `.timeout(..)` does not type-check.
+///
+/// Each transition method consumes `self`, returns the matching
+/// [`ModuleEvent`] for that transition alongside the next-state flow, and
+/// carries no state of its own between transitions - so no event is ever
+/// overwritten or silently dropped. A happy-path caller is expected to emit
+/// every event it's handed:
+///
+/// ```ignore
+/// let (transfer_ev, flow) = TransferFlow::<Sent>::begin(transfer);
+/// ctx.emit(transfer_ev);
+/// let (recv_ev, flow) = flow.received(recv);
+/// ctx.emit(recv_ev);
+/// let (ack_ev, _flow) = flow.acknowledge(ack);
+/// ctx.emit(ack_ev);
+/// ```
+pub struct TransferFlow<S> {
+    _state: PhantomData<S>,
+}
+
+impl<S> TransferFlow<S> {
+    fn advance() -> Self {
+        Self {
+            _state: PhantomData,
+        }
+    }
+}
+
+impl TransferFlow<Sent> {
+    /// Begins a new flow, emitting the [`TransferEvent`] for the send of the
+    /// packet on the source chain.
+    pub fn begin(transfer: TransferEvent) -> (ModuleEvent, Self) {
+        (Event::Transfer(transfer).into(), Self::advance())
+    }
+
+    /// Records that the packet was received on the counterparty chain,
+    /// emitting the matching [`RecvEvent`].
+    pub fn received(self, recv: RecvEvent) -> (ModuleEvent, TransferFlow<Received>) {
+        (Event::Recv(recv).into(), TransferFlow::advance())
+    }
+}
+
+macro_rules! impl_pre_ack_transitions {
+    ($($state:ty),* $(,)?) => {
+        $(
+            impl TransferFlow<$state> {
+                /// Records that the source chain processed the
+                /// acknowledgement, emitting the matching [`AckEvent`].
+                pub fn acknowledge(self, ack: AckEvent) -> (ModuleEvent, TransferFlow<Acknowledged>) {
+                    (Event::Ack(ack).into(), TransferFlow::advance())
+                }
+
+                /// Records the success/error status of the acknowledgement,
+                /// emitting the matching [`AckStatusEvent`].
+                pub fn ack_status(self, status: AckStatusEvent) -> (ModuleEvent, TransferFlow<Acknowledged>) {
+                    (Event::AckStatus(status).into(), TransferFlow::advance())
+                }
+
+                /// Records that the packet timed out before being
+                /// acknowledged, emitting the matching [`TimeoutEvent`].
+                pub fn timeout(self, timeout: TimeoutEvent) -> (ModuleEvent, TransferFlow<TimedOut>) {
+                    (Event::Timeout(timeout).into(), TransferFlow::advance())
+                }
+            }
+        )*
+    };
+}
+
+impl_pre_ack_transitions!(Sent, Received);
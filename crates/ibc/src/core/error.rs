@@ -0,0 +1,53 @@
+//! Crate-wide decoding errors.
+//!
+//! Every module that parses raw protobuf bytes or an `Any` type-url
+//! (commitment proofs, client/consensus states, headers, ...) constructs its
+//! failures through [`DecodingError`] instead of defining its own near-
+//! identical variants, so consumers can match decode failures on one stable
+//! surface regardless of which module produced them.
+
+use flex_error::{define_error, TraceError};
+use prost::DecodeError;
+
+define_error! {
+    #[derive(Debug)]
+    DecodingError {
+        Proto
+            [ TraceError<DecodeError> ]
+            | _ | { "failed to decode protobuf bytes" },
+
+        UnknownTypeUrl
+            { type_url: String }
+            | e | {
+                format_args!("unknown type URL `{0}`", e.type_url)
+            },
+
+        MismatchedTypeUrls
+            {
+                expected: String,
+                found: String,
+            }
+            | e | {
+                format_args!(
+                    "mismatched type URLs: expected `{0}`, found `{1}`",
+                    e.expected, e.found
+                )
+            },
+
+        MismatchedLength
+            {
+                expected: usize,
+                found: usize,
+            }
+            | e | {
+                format_args!(
+                    "mismatched length: expected `{0}`, found `{1}`",
+                    e.expected, e.found
+                )
+            },
+
+        MissingRawData
+            { description: String }
+            | e | { format_args!("missing raw data: {0}", e.description) },
+    }
+}
@@ -0,0 +1,20 @@
+use flex_error::{define_error, TraceError};
+
+pub use crate::core::error::DecodingError;
+use crate::core::timestamp::ParseTimestampError;
+
+define_error! {
+    #[derive(Debug)]
+    ClientError {
+        Decoding
+            [ DecodingError ]
+            | _ | { "client decoding failed" },
+
+        MissingRawHeader
+            | _ | { "missing raw header" },
+
+        InvalidPacketTimestamp
+            [ TraceError<ParseTimestampError> ]
+            | _ | { "invalid packet timestamp" },
+    }
+}
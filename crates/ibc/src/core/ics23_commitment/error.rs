@@ -1,37 +1,40 @@
-use displaydoc::Display;
-use prost::DecodeError;
-
-#[derive(Debug, Display)]
-pub enum Error {
-    /// invalid raw merkle proof
-    InvalidRawMerkleProof(DecodeError),
-    /// failed to decode commitment proof
-    CommitmentProofDecodingFailed(DecodeError),
-    /// empty commitment prefix
-    EmptyCommitmentPrefix,
-    /// empty merkle proof
-    EmptyMerkleProof,
-    /// empty merkle root
-    EmptyMerkleRoot,
-    /// empty verified value
-    EmptyVerifiedValue,
-    /// mismatch between the number of proofs with that of specs
-    NumberOfSpecsMismatch,
-    /// mismatch between the number of proofs with that of keys
-    NumberOfKeysMismatch,
-    /// invalid merkle proof
-    InvalidMerkleProof,
-    /// proof verification failed
-    VerificationFailure,
-}
+//! [`Error`] is built with `flex-error`'s `define_error!`, so each variant
+//! carries a trace through whichever tracer `flex-error` itself is
+//! configured with; `Decoding` also carries its [`DecodingError`] source.
+
+use flex_error::define_error;
+
+pub use crate::core::error::DecodingError;
+
+define_error! {
+    #[derive(Debug)]
+    Error {
+        Decoding
+            [ DecodingError ]
+            | _ | { "commitment proof decoding failed" },
+
+        EmptyCommitmentPrefix
+            | _ | { "empty commitment prefix" },
+
+        EmptyMerkleProof
+            | _ | { "empty merkle proof" },
+
+        EmptyMerkleRoot
+            | _ | { "empty merkle root" },
+
+        EmptyVerifiedValue
+            | _ | { "empty verified value" },
+
+        NumberOfSpecsMismatch
+            | _ | { "mismatch between the number of proofs with that of specs" },
+
+        NumberOfKeysMismatch
+            | _ | { "mismatch between the number of proofs with that of keys" },
+
+        InvalidMerkleProof
+            | _ | { "invalid merkle proof" },
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self {
-            Error::InvalidRawMerkleProof(e) => Some(e),
-            Error::CommitmentProofDecodingFailed(e) => Some(e),
-            _ => None,
-        }
+        VerificationFailure
+            | _ | { "proof verification failed" },
     }
 }
@@ -5,6 +5,7 @@ use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::mock::Header as RawMockHeader;
 use ibc_proto::protobuf::Protobuf;
 
+use crate::core::error::DecodingError;
 use crate::core::ics02_client::error::ClientError;
 use crate::core::ics02_client::header::Header;
 use crate::core::timestamp::Timestamp;
@@ -48,10 +49,10 @@ impl TryFrom<RawMockHeader> for MockHeader {
             height: raw
                 .height
                 .and_then(|raw_height| raw_height.try_into().ok())
-                .ok_or(ClientError::MissingRawHeader)?,
+                .ok_or_else(ClientError::missing_raw_header)?,
 
             timestamp: Timestamp::from_nanoseconds(raw.timestamp)
-                .map_err(ClientError::InvalidPacketTimestamp)?,
+                .map_err(ClientError::invalid_packet_timestamp)?,
         })
     }
 }
@@ -107,10 +108,10 @@ impl TryFrom<Any> for MockHeader {
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         match raw.type_url.as_str() {
             MOCK_HEADER_TYPE_URL => Ok(Protobuf::<RawMockHeader>::decode_vec(&raw.value)
-                .map_err(ClientError::InvalidRawHeader)?),
-            _ => Err(ClientError::UnknownHeaderType {
-                header_type: raw.type_url,
-            }),
+                .map_err(|e| ClientError::decoding(DecodingError::proto(e)))?),
+            _ => Err(ClientError::decoding(DecodingError::unknown_type_url(
+                raw.type_url,
+            ))),
         }
     }
 }